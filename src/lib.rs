@@ -59,15 +59,83 @@
 //! Again, while it's also possible to unzip [`Result`]s with right-recursively
 //! zipped tuples, I found that these occur much less often.
 //!
+//! ## Unzipping partially-nested tuples
+//! [`UnzipInto::unzip_into`] also handles tuples of three or more elements
+//! where exactly one slot is itself a pair, or a left- or right-recursive
+//! triple, rather than only fully left- or right-recursive shapes.
+//!
+//! ```rust
+//! use zipped::UnzipInto;
+//!
+//! let (a, b, c, d) = ((1, 2), 3, 4).unzip_into();
+//! let (a, b, c, d) = (1, (2, 3), 4).unzip_into();
+//! let (a, b, c, d, e) = (((1, 2), 3), 4, 5).unzip_into();
+//! ```
+//!
+//! ## Zipping `(A, B, C, ...)` back into a nested tuple
+//! [`ZipLeftInto::zip_left_into`] and [`ZipRightInto::zip_right_into`] are the
+//! inverse of [`UnzipInto::unzip_into`]: they turn a flat tuple back into a
+//! left- or right-recursively zipped one. This works for up to 26 tuple
+//! elements and also lifts through [`Option`] and [`Result`].
+//!
+//! ```rust
+//! use zipped::{UnzipInto, ZipLeftInto};
+//!
+//! let flat: (i32, i32, i32) = ((1, 2), 3).unzip_into();
+//! let nested: ((i32, i32), i32) = flat.zip_left_into();
+//!
+//! assert_eq!(nested, ((1, 2), 3));
+//! ```
+//!
+//! ## Collecting an iterator of zipped tuples into a tuple of collections
+//! [`UnzipCollect::unzip_collect`] drains an iterator of left- or
+//! right-recursively zipped tuples directly into a flat tuple of
+//! [`Extend`]able collections, one column per element.
+//!
+//! ```rust
+//! use zipped::UnzipCollect;
+//!
+//! let zipped = vec![((1, 2), 3), ((4, 5), 6)];
+//! let (a, b, c): (Vec<i32>, Vec<i32>, Vec<i32>) = zipped.into_iter().unzip_collect();
+//!
+//! assert_eq!(a, vec![1, 4]);
+//! assert_eq!(b, vec![2, 5]);
+//! assert_eq!(c, vec![3, 6]);
+//! ```
+//!
+//! With the `rayon` feature enabled, `ParallelUnzipCollect` offers the same
+//! thing for rayon's `ParallelIterator`s.
+//!
+//! ## Mapping straight into a tuple of collections
+//! [`UnzipMap::unzip_map`] fuses a tuple-returning closure with
+//! [`UnzipCollect::unzip_collect`], so the intermediate zipped tuples never
+//! get collected into a `Vec` in between.
+//!
+//! ```rust
+//! use zipped::UnzipMap;
+//!
+//! let (a, b, c): (Vec<i32>, Vec<i32>, Vec<i32>) =
+//!     vec![1, 2].into_iter().unzip_map(|x| ((x, x * 2), x * 3));
+//!
+//! assert_eq!(a, vec![1, 2]);
+//! assert_eq!(b, vec![2, 4]);
+//! assert_eq!(c, vec![3, 6]);
+//! ```
+//!
 //! # Limitations
 //! - __Type inference.__ The compiler cannot automatically infer `T` in
 //!   [`UnzipInto<T>`]. Eventually, you will need to specify the return value's
 //!   arity.
 //! - __Maximum arity.__ [`UnzipFrom`] is implemented for tuples of up to 26
 //!   elements.
-//! - __Strict.__ It only works for completely zipped tuples where each tuple
-//!   contains 2 elements and only the left (or the right) element can be
-//!   another tuple, i.e. it does not work for `((A, B), C, D)`.
+//! - __Strict.__ It handles fully left- or right-recursive tuples, as well as
+//!   tuples of three or more elements where exactly one slot is itself a pair
+//!   or a left- or right-recursive triple, e.g. `((A, B), C, D)`,
+//!   `(A, (B, C), D)` and `(((A, B), C), D, E)` all flatten to their matching
+//!   flat tuple. It does not handle more than one such slot, e.g.
+//!   `((A, B), (C, D))`, nor a slot nested more than two levels deep, e.g.
+//!   `((((A, B), C), D), E, F)`, nor a two-element tuple that is not fully
+//!   left- or right-recursive, e.g. `((A, (B, C)), D)`.
 
 /// Value-to-value conversion analogous to [`Into`] that consumes an input value
 /// of this type and returns an unzipped equivalent of type `T`.
@@ -166,10 +234,328 @@ impl<A, B> UnzipFrom<(A, B)> for (A, B) {
 
 nested!(A B C D E F G H I J K L M N O P Q R S T U V W X Y Z);
 
+// Relaxes the strictness of `nested!` above: these impls let exactly one slot
+// of an otherwise-flat tuple itself be a pair, so e.g. `((A, B), C, D)` and
+// `(A, (B, C), D)` both flatten to `(A, B, C, D)`. Letters are reused as both
+// the generic parameters and the destructured bindings, same as `nested!`.
+macro_rules! partial {
+    ($a:ident $b:ident $c:ident $($ident:ident)*) => {
+        partial!(@ $a $b $c ; $($ident)*);
+    };
+    (@ $($ident:ident)* ; $next:ident $($rest:ident)*) => {
+        partial!(@ $($ident)* ;);
+        partial!(@ $($ident)* $next ; $($rest)*);
+    };
+    (@ $($ident:ident)* ;) => {
+        partial!(splice ; $($ident)*);
+    };
+    (splice $($before:ident)* ; $mid:ident $($after:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<$($before,)* $mid, __Rhs, $($after,)*>
+            UnzipFrom<($($before,)* ($mid, __Rhs), $($after,)*)>
+            for ($($before,)* $mid, __Rhs, $($after,)*)
+        {
+            fn unzip_from(tuple: ($($before,)* ($mid, __Rhs), $($after,)*)) -> Self {
+                let ($($before,)* ($mid, __Rhs), $($after,)*) = tuple;
+                ($($before,)* $mid, __Rhs, $($after,)*)
+            }
+        }
+
+        partial!(splice $($before)* $mid ; $($after)*);
+    };
+    (splice $($before:ident)* ;) => {};
+}
+
+// Bounded to 25 letters (rather than 26) so the flattened output, which gains
+// one element from the spliced-in pair, stays within the 26-element cap.
+partial!(A B C D E F G H I J K L M N O P Q R S T U V W X Y);
+
+// Further relaxes `partial!` above: the one splicable slot may itself be a
+// left- or right-recursive triple, not only a plain pair, recursing one
+// level deeper through `UnzipFrom` on that slot, e.g. both `(((A, B), C), D,
+// E)` and `(A, (B, (C, D)), E)` flatten to `(A, B, C, D, E)`. This is
+// generated as its own pair of impls (rather than expressed generically over
+// "whatever `UnzipFrom<Slot>`" for a free `Slot`) because a free,
+// unconstrained `Slot` type parameter would make every position's impl a
+// blanket over any type, which conflicts with every other position's impl
+// under the same substitution. Tying the slot to a literal nested-pair shape,
+// same as `partial!` does one level up, keeps each position's impl distinct.
+//
+// Only goes one level deeper than `partial!`, not arbitrarily deep: going
+// further would mean generating a new tier of impls per extra level (another
+// combinatorial pass over positions, each shrinking the letter budget by one
+// more), so this stops short of the fully general "recurse through
+// `UnzipFrom` to any depth" the relaxation could in principle reach.
+macro_rules! partial_nested {
+    ($a:ident $b:ident $c:ident $($ident:ident)*) => {
+        partial_nested!(@ $a $b $c ; $($ident)*);
+    };
+    (@ $($ident:ident)* ; $next:ident $($rest:ident)*) => {
+        partial_nested!(@ $($ident)* ;);
+        partial_nested!(@ $($ident)* $next ; $($rest)*);
+    };
+    (@ $($ident:ident)* ;) => {
+        partial_nested!(splice ; $($ident)*);
+    };
+    (splice $($before:ident)* ; $mid:ident $($after:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<$($before,)* $mid, __Rhs1, __Rhs2, $($after,)*>
+            UnzipFrom<($($before,)* (($mid, __Rhs1), __Rhs2), $($after,)*)>
+            for ($($before,)* $mid, __Rhs1, __Rhs2, $($after,)*)
+        {
+            fn unzip_from(
+                tuple: ($($before,)* (($mid, __Rhs1), __Rhs2), $($after,)*),
+            ) -> Self {
+                let ($($before,)* (($mid, __Rhs1), __Rhs2), $($after,)*) = tuple;
+                ($($before,)* $mid, __Rhs1, __Rhs2, $($after,)*)
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$($before,)* $mid, __Rhs1, __Rhs2, $($after,)*>
+            UnzipFrom<($($before,)* ($mid, (__Rhs1, __Rhs2)), $($after,)*)>
+            for ($($before,)* $mid, __Rhs1, __Rhs2, $($after,)*)
+        {
+            fn unzip_from(
+                tuple: ($($before,)* ($mid, (__Rhs1, __Rhs2)), $($after,)*),
+            ) -> Self {
+                let ($($before,)* ($mid, (__Rhs1, __Rhs2)), $($after,)*) = tuple;
+                ($($before,)* $mid, __Rhs1, __Rhs2, $($after,)*)
+            }
+        }
+
+        partial_nested!(splice $($before)* $mid ; $($after)*);
+    };
+    (splice $($before:ident)* ;) => {};
+}
+
+// Bounded to 24 letters (rather than 25) so the flattened output, which
+// gains two elements from the spliced-in triple, stays within the
+// 26-element cap.
+partial_nested!(A B C D E F G H I J K L M N O P Q R S T U V W X);
+
+/// Value-to-value conversion analogous to [`Into`] that consumes a flat input
+/// value of this type and returns a left-recursively zipped equivalent of
+/// type `T`. This is the inverse of [`UnzipInto`].
+pub trait ZipLeftInto<T> {
+    /// Zips this type into its left-recursive equivalent `T`.
+    fn zip_left_into(self) -> T;
+}
+
+impl<T, U> ZipLeftInto<T> for U
+where
+    T: ZipLeftFrom<U>,
+{
+    fn zip_left_into(self) -> T {
+        T::zip_left_from(self)
+    }
+}
+
+/// Value-to-value conversation analogous to [`From`] that consumes a flat
+/// value of type `T` and returns a left-recursively zipped equivalent of this
+/// type. This is the inverse of [`UnzipFrom`].
+pub trait ZipLeftFrom<T> {
+    /// Zips flat `tuple` into this left-recursively nested type.
+    fn zip_left_from(tuple: T) -> Self;
+}
+
+/// Value-to-value conversion analogous to [`Into`] that consumes a flat input
+/// value of this type and returns a right-recursively zipped equivalent of
+/// type `T`. This is the inverse of [`UnzipInto`].
+pub trait ZipRightInto<T> {
+    /// Zips this type into its right-recursive equivalent `T`.
+    fn zip_right_into(self) -> T;
+}
+
+impl<T, U> ZipRightInto<T> for U
+where
+    T: ZipRightFrom<U>,
+{
+    fn zip_right_into(self) -> T {
+        T::zip_right_from(self)
+    }
+}
+
+/// Value-to-value conversation analogous to [`From`] that consumes a flat
+/// value of type `T` and returns a right-recursively zipped equivalent of
+/// this type. This is the inverse of [`UnzipFrom`].
+pub trait ZipRightFrom<T> {
+    /// Zips flat `tuple` into this right-recursively nested type.
+    fn zip_right_from(tuple: T) -> Self;
+}
+
+impl<T, U> ZipLeftFrom<Option<T>> for Option<U>
+where
+    U: ZipLeftFrom<T>,
+{
+    fn zip_left_from(tuple: Option<T>) -> Self {
+        tuple.map(ZipLeftFrom::zip_left_from)
+    }
+}
+
+impl<T, U> ZipRightFrom<Option<T>> for Option<U>
+where
+    U: ZipRightFrom<T>,
+{
+    fn zip_right_from(tuple: Option<T>) -> Self {
+        tuple.map(ZipRightFrom::zip_right_from)
+    }
+}
+
+impl<T, E, U> ZipLeftFrom<Result<T, E>> for Result<U, E>
+where
+    U: ZipLeftFrom<T>,
+{
+    fn zip_left_from(tuple: Result<T, E>) -> Self {
+        tuple.map(ZipLeftFrom::zip_left_from)
+    }
+}
+
+impl<T, E, U> ZipRightFrom<Result<T, E>> for Result<U, E>
+where
+    U: ZipRightFrom<T>,
+{
+    fn zip_right_from(tuple: Result<T, E>) -> Self {
+        tuple.map(ZipRightFrom::zip_right_from)
+    }
+}
+
+impl<A> ZipLeftFrom<(A,)> for (A,) {
+    fn zip_left_from(tuple: (A,)) -> Self {
+        tuple
+    }
+}
+
+impl<A> ZipRightFrom<(A,)> for (A,) {
+    fn zip_right_from(tuple: (A,)) -> Self {
+        tuple
+    }
+}
+
+impl<A, B> ZipLeftFrom<(A, B)> for (A, B) {
+    fn zip_left_from(tuple: (A, B)) -> Self {
+        tuple
+    }
+}
+
+impl<A, B> ZipRightFrom<(A, B)> for (A, B) {
+    fn zip_right_from(tuple: (A, B)) -> Self {
+        tuple
+    }
+}
+
+macro_rules! nested_zip {
+    ($a:ident $b:ident $c:ident $($ident:ident)*) => {
+        nested_zip!(@ $a $b $c ; $($ident)*);
+    };
+    (@ $($ident:ident)* ; $next:ident $($rest:ident)*) => {
+        nested_zip!(@ $($ident)* ;);
+        nested_zip!(@ $($ident)* $next ; $($rest)*);
+    };
+    (@ $($ident:ident)* ;) => {
+        #[allow(non_snake_case)]
+        impl<$($ident,)*> ZipLeftFrom<($($ident,)*)> for left!($($ident)*) {
+            fn zip_left_from(tuple: ($($ident,)*)) -> Self {
+                let ($($ident,)*) = tuple;
+                left!($($ident)*)
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$($ident,)*> ZipRightFrom<($($ident,)*)> for right!($($ident)*) {
+            fn zip_right_from(tuple: ($($ident,)*)) -> Self {
+                let ($($ident,)*) = tuple;
+                right!($($ident)*)
+            }
+        }
+    }
+}
+
+nested_zip!(A B C D E F G H I J K L M N O P Q R S T U V W X Y Z);
+
+/// Collects an iterator of recursively-zipped tuples directly into a flat
+/// tuple of collections, one column per element, without materializing the
+/// intermediate zipped items. `T` is the flat tuple each item unzips into; it
+/// is inferred from the item type once the arity of `C` is known, e.g. from a
+/// type annotation on the result.
+///
+/// Unlike a hand-written `unzip_collect` for a concrete column type, this
+/// does not reserve capacity up front from [`Iterator::size_hint`]: stable
+/// [`Extend`] has no `reserve`/`extend_reserve` hook to call it through
+/// generically, so each column grows the way [`Extend::extend`] already
+/// does for it. A `C: Extend<T>` bound alone can't get that reservation
+/// back.
+pub trait UnzipCollect<T, C>: Iterator {
+    /// Unzips each item of this iterator into `T` and collects the columns
+    /// into `C`.
+    fn unzip_collect(self) -> C;
+}
+
+macro_rules! unzip_collect {
+    ($a:ident:$ca:ident $($ident:ident:$coll:ident)*) => {
+        unzip_collect!(@ $a:$ca ; $($ident:$coll)*);
+    };
+    (@ $($ident:ident:$coll:ident)* ; $next:ident:$nextc:ident $($rest:ident:$restc:ident)*) => {
+        unzip_collect!(@ $($ident:$coll)* ;);
+        unzip_collect!(@ $($ident:$coll)* $next:$nextc ; $($rest:$restc)*);
+    };
+    (@ $($ident:ident:$coll:ident)* ;) => {
+        #[allow(non_snake_case)]
+        impl<Iter, $($ident,)* $($coll,)*> UnzipCollect<($($ident,)*), ($($coll,)*)> for Iter
+        where
+            Iter: Iterator,
+            Iter::Item: UnzipInto<($($ident,)*)>,
+            $($coll: Default + Extend<$ident>,)*
+        {
+            fn unzip_collect(self) -> ($($coll,)*) {
+                // Built field-by-field rather than via `<(...) as Default>`,
+                // since std only implements `Default` for tuples up to 12
+                // elements and this macro goes up to 26.
+                $(let mut $coll = <$coll as Default>::default();)*
+
+                for item in self {
+                    let ($($ident,)*) = item.unzip_into();
+                    $($coll.extend(std::iter::once($ident));)*
+                }
+
+                ($($coll,)*)
+            }
+        }
+    }
+}
+
+unzip_collect!(
+    A:Ca B:Cb C:Cc D:Cd E:Ce F:Cf G:Cg H:Ch I:Ci J:Cj K:Ck L:Cl M:Cm
+    N:Cn O:Co P:Cp Q:Cq R:Cr S:Cs T:Ct U:Cu V:Cv W:Cw X:Cx Y:Cy Z:Cz
+);
+
+#[cfg(feature = "rayon")]
+mod rayon;
+
+#[cfg(feature = "rayon")]
+pub use crate::rayon::ParallelUnzipCollect;
+
+/// Extension trait that fuses mapping with [`UnzipCollect`], so a
+/// tuple-returning closure can be scattered straight into a flat tuple of
+/// collections without materializing the intermediate zipped items.
+pub trait UnzipMap: Iterator + Sized {
+    /// Maps each item of this iterator with `f` and collects the columns of
+    /// the resulting recursively-zipped tuples into `C`, in the same pass.
+    fn unzip_map<F, R, T, C>(self, f: F) -> C
+    where
+        F: FnMut(Self::Item) -> R,
+        std::iter::Map<Self, F>: UnzipCollect<T, C>,
+    {
+        self.map(f).unzip_collect()
+    }
+}
+
+impl<Iter> UnzipMap for Iter where Iter: Iterator {}
+
 #[cfg(test)]
 #[allow(unused)]
 mod tests {
-    use super::{UnzipFrom, UnzipInto};
+    use super::{UnzipCollect, UnzipFrom, UnzipInto, UnzipMap, ZipLeftInto, ZipRightInto};
 
     #[test]
     fn test_left_recursive_tuple() {
@@ -202,4 +588,100 @@ mod tests {
             Err(_) => {}
         }
     }
+
+    #[test]
+    fn test_zip_left_into() {
+        let nested: ((i32, i32), i32) = (1, 2, 3).zip_left_into();
+
+        assert_eq!(nested, ((1, 2), 3));
+    }
+
+    #[test]
+    fn test_zip_right_into() {
+        let nested: (i32, (i32, i32)) = (1, 2, 3).zip_right_into();
+
+        assert_eq!(nested, (1, (2, 3)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let original = ((1, 2), 3);
+        let flat: (i32, i32, i32) = original.unzip_into();
+        let nested: ((i32, i32), i32) = flat.zip_left_into();
+
+        assert_eq!(original, nested);
+    }
+
+    #[test]
+    fn test_unzip_collect() {
+        let zipped = vec![((1, 2), 3), ((4, 5), 6)];
+        let (a, b, c): (Vec<i32>, Vec<i32>, Vec<i32>) = zipped.into_iter().unzip_collect();
+
+        assert_eq!(a, vec![1, 4]);
+        assert_eq!(b, vec![2, 5]);
+        assert_eq!(c, vec![3, 6]);
+    }
+
+    #[test]
+    fn test_unzip_map() {
+        let (a, b, c): (Vec<i32>, Vec<i32>, Vec<i32>) =
+            vec![1, 2].into_iter().unzip_map(|x| ((x, x * 2), x * 3));
+
+        assert_eq!(a, vec![1, 2]);
+        assert_eq!(b, vec![2, 4]);
+        assert_eq!(c, vec![3, 6]);
+    }
+
+    #[test]
+    fn test_partially_nested_tuple_first() {
+        let (a, b, c, d) = ((1, 2), 3, 4).unzip_into();
+
+        assert_eq!((a, b, c, d), (1, 2, 3, 4));
+    }
+
+    #[test]
+    fn test_partially_nested_tuple_middle() {
+        let (a, b, c, d) = (1, (2, 3), 4).unzip_into();
+
+        assert_eq!((a, b, c, d), (1, 2, 3, 4));
+    }
+
+    #[test]
+    fn test_partially_nested_tuple_two_levels_deep() {
+        let (a, b, c, d, e) = (((1, 2), 3), 4, 5).unzip_into();
+
+        assert_eq!((a, b, c, d, e), (1, 2, 3, 4, 5));
+    }
+
+    #[test]
+    fn test_zip_left_into_option() {
+        let flat = Some((1, 2, 3));
+        let nested: Option<((i32, i32), i32)> = flat.zip_left_into();
+
+        assert_eq!(nested, Some(((1, 2), 3)));
+    }
+
+    #[test]
+    fn test_zip_right_into_option() {
+        let flat = Some((1, 2, 3));
+        let nested: Option<(i32, (i32, i32))> = flat.zip_right_into();
+
+        assert_eq!(nested, Some((1, (2, 3))));
+    }
+
+    #[test]
+    fn test_zip_left_into_result() {
+        let flat = Ok::<_, ()>((1, 2, 3));
+        let nested: Result<((i32, i32), i32), ()> = flat.zip_left_into();
+
+        assert_eq!(nested, Ok(((1, 2), 3)));
+    }
+
+    #[test]
+    fn test_zip_right_into_result() {
+        let flat = Ok::<_, ()>((1, 2, 3));
+        let nested: Result<(i32, (i32, i32)), ()> = flat.zip_right_into();
+
+        assert_eq!(nested, Ok((1, (2, 3))));
+    }
 }
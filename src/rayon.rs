@@ -0,0 +1,79 @@
+//! Parallel counterpart of [`crate::UnzipCollect`], built on top of `rayon`.
+
+use rayon::iter::{IntoParallelIterator, ParallelExtend, ParallelIterator};
+
+use crate::UnzipInto;
+
+/// Collects a [`ParallelIterator`] of recursively-zipped tuples directly into
+/// a flat tuple of collections, one column per element, mirroring rayon's own
+/// `MultiZip` in the opposite direction. `T` is the flat tuple each item
+/// unzips into; it is inferred from the item type once the arity of `C` is
+/// known, e.g. from a type annotation on the result.
+pub trait ParallelUnzipCollect<T, C>: ParallelIterator {
+    /// Unzips each item of this iterator in parallel and collects the
+    /// columns into `C`.
+    fn unzip_collect(self) -> C;
+}
+
+macro_rules! par_unzip_collect {
+    ($a:ident:$ca:ident $($ident:ident:$coll:ident)*) => {
+        par_unzip_collect!(@ $a:$ca ; $($ident:$coll)*);
+    };
+    (@ $($ident:ident:$coll:ident)* ; $next:ident:$nextc:ident $($rest:ident:$restc:ident)*) => {
+        par_unzip_collect!(@ $($ident:$coll)* ;);
+        par_unzip_collect!(@ $($ident:$coll)* $next:$nextc ; $($rest:$restc)*);
+    };
+    (@ $($ident:ident:$coll:ident)* ;) => {
+        #[allow(non_snake_case)]
+        impl<Iter, $($ident,)* $($coll,)*> ParallelUnzipCollect<($($ident,)*), ($($coll,)*)> for Iter
+        where
+            Iter: ParallelIterator,
+            Iter::Item: UnzipInto<($($ident,)*)>,
+            $($ident: Send,)*
+            $($coll: Default + Send + Extend<$ident> + ParallelExtend<$ident> + IntoParallelIterator<Item = $ident>,)*
+        {
+            fn unzip_collect(self) -> ($($coll,)*) {
+                // Built field-by-field rather than via `<(...) as Default>`,
+                // since std only implements `Default` for tuples up to 12
+                // elements and this macro goes up to 26.
+                self.map(UnzipInto::unzip_into).fold(
+                    || ($(<$coll as Default>::default(),)*),
+                    |($(mut $coll,)*), item| {
+                        let ($($ident,)*) = item;
+                        $($coll.extend(std::iter::once($ident));)*
+                        ($($coll,)*)
+                    },
+                ).reduce(
+                    || ($(<$coll as Default>::default(),)*),
+                    |($(mut $coll,)*), ($($ident,)*)| {
+                        $($coll.par_extend($ident.into_par_iter());)*
+                        ($($coll,)*)
+                    },
+                )
+            }
+        }
+    }
+}
+
+par_unzip_collect!(
+    A:Ca B:Cb C:Cc D:Cd E:Ce F:Cf G:Cg H:Ch I:Ci J:Cj K:Ck L:Cl M:Cm
+    N:Cn O:Co P:Cp Q:Cq R:Cr S:Cs T:Ct U:Cu V:Cv W:Cw X:Cx Y:Cy Z:Cz
+);
+
+#[cfg(test)]
+#[allow(unused)]
+mod tests {
+    use rayon::iter::IntoParallelIterator;
+
+    use super::ParallelUnzipCollect;
+
+    #[test]
+    fn test_parallel_unzip_collect() {
+        let zipped = vec![((1, 2), 3), ((4, 5), 6)];
+        let (a, b, c): (Vec<i32>, Vec<i32>, Vec<i32>) = zipped.into_par_iter().unzip_collect();
+
+        assert_eq!(a, vec![1, 4]);
+        assert_eq!(b, vec![2, 5]);
+        assert_eq!(c, vec![3, 6]);
+    }
+}